@@ -1,13 +1,33 @@
-use anyhow::Result;
-use clap::Parser;
-use periscope::{client::MassiveClient, Config, OptionsChainResponse};
+use anyhow::{anyhow, Result};
+use clap::{Args, Parser, Subcommand};
+use periscope::client::{MassiveClient, OptionsChainParams};
+use periscope::models::{ContractType, OptionSymbol};
+use periscope::{Config, OptionContract, OptionsChainResponse};
+use std::fs::File;
+use std::path::PathBuf;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser, Debug)]
 #[command(name = "greeks_test")]
-#[command(about = "Fetch and display options chain data with Greeks")]
-struct Args {
+#[command(about = "Inspect options chains, single contracts, and screens")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Fetch and display an options chain snapshot
+    Chain(ChainArgs),
+    /// Fetch a single contract by its full OCC ticker
+    Contract(ContractArgs),
+    /// List contracts matching a delta band or open-interest threshold
+    Screen(ScreenArgs),
+}
+
+#[derive(Args, Debug)]
+struct ChainArgs {
     /// Underlying ticker symbol
     #[arg(short, long, default_value = "AAPL")]
     ticker: String,
@@ -16,9 +36,50 @@ struct Args {
     #[arg(short, long, default_value = "10")]
     limit: i32,
 
+    /// Filter to a specific strike price
+    #[arg(long)]
+    strike: Option<f64>,
+
+    /// Filter to a specific expiration date (YYYY-MM-DD)
+    #[arg(long)]
+    expiration: Option<String>,
+
+    /// Filter to "call" or "put"
+    #[arg(long = "type")]
+    contract_type: Option<String>,
+
     /// Output raw JSON
     #[arg(long)]
     json: bool,
+
+    /// Write the chain to a CSV file instead of printing it
+    #[arg(long)]
+    csv: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct ContractArgs {
+    /// Full OCC option ticker, e.g. O:AAPL251219C00150000
+    ticker: String,
+}
+
+#[derive(Args, Debug)]
+struct ScreenArgs {
+    /// Underlying ticker symbol
+    #[arg(short, long, default_value = "AAPL")]
+    ticker: String,
+
+    /// Minimum delta (inclusive)
+    #[arg(long)]
+    min_delta: Option<f64>,
+
+    /// Maximum delta (inclusive)
+    #[arg(long)]
+    max_delta: Option<f64>,
+
+    /// Minimum open interest (inclusive)
+    #[arg(long)]
+    min_open_interest: Option<i64>,
 }
 
 fn print_options_chain(data: &OptionsChainResponse) {
@@ -28,139 +89,152 @@ fn print_options_chain(data: &OptionsChainResponse) {
     println!("Total contracts returned: {}\n", data.results.len());
 
     for option in &data.results {
-        if let Some(details) = &option.details {
-            println!(
-                "Contract: {}",
-                details.ticker.as_deref().unwrap_or("N/A")
-            );
-            println!(
-                "  Type: {}",
-                details
-                    .contract_type
-                    .as_deref()
-                    .unwrap_or("N/A")
-                    .to_uppercase()
-            );
-            println!(
-                "  Strike: ${}",
-                details
-                    .strike_price
-                    .map(|p| p.to_string())
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-            println!(
-                "  Expiration: {}",
-                details.expiration_date.as_deref().unwrap_or("N/A")
-            );
-            println!(
-                "  Exercise Style: {}",
-                details.exercise_style.as_deref().unwrap_or("N/A")
-            );
-        }
-
-        println!("  Greeks:");
-        if let Some(greeks) = &option.greeks {
-            println!(
-                "    Delta: {}",
-                greeks
-                    .delta
-                    .map(|v| format!("{:.6}", v))
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-            println!(
-                "    Gamma: {}",
-                greeks
-                    .gamma
-                    .map(|v| format!("{:.6}", v))
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-            println!(
-                "    Theta: {}",
-                greeks
-                    .theta
-                    .map(|v| format!("{:.6}", v))
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-            println!(
-                "    Vega:  {}",
-                greeks
-                    .vega
-                    .map(|v| format!("{:.6}", v))
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-        } else {
-            println!("    N/A");
-        }
+        print_contract(option);
+    }
+}
 
+fn print_contract(option: &OptionContract) {
+    if let Some(details) = &option.details {
+        println!("Contract: {}", details.ticker.as_deref().unwrap_or("N/A"));
+        println!(
+            "  Type: {}",
+            details
+                .contract_type
+                .as_deref()
+                .unwrap_or("N/A")
+                .to_uppercase()
+        );
         println!(
-            "  Implied Volatility: {}",
-            option
-                .implied_volatility
-                .map(|v| format!("{:.4}", v))
+            "  Strike: ${}",
+            details
+                .strike_price
+                .map(|p| p.to_string())
                 .unwrap_or_else(|| "N/A".to_string())
         );
         println!(
-            "  Open Interest: {}",
-            option
-                .open_interest
-                .map(|v| v.to_string())
+            "  Expiration: {}",
+            details.expiration_date.as_deref().unwrap_or("N/A")
+        );
+        println!(
+            "  Exercise Style: {}",
+            details.exercise_style.as_deref().unwrap_or("N/A")
+        );
+    }
+
+    println!("  Greeks:");
+    if let Some(greeks) = &option.greeks {
+        println!(
+            "    Delta: {}",
+            greeks
+                .delta
+                .map(|v| format!("{:.6}", v))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+        println!(
+            "    Gamma: {}",
+            greeks
+                .gamma
+                .map(|v| format!("{:.6}", v))
                 .unwrap_or_else(|| "N/A".to_string())
         );
+        println!(
+            "    Theta: {}",
+            greeks
+                .theta
+                .map(|v| format!("{:.6}", v))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+        println!(
+            "    Vega:  {}",
+            greeks
+                .vega
+                .map(|v| format!("{:.6}", v))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+    } else {
+        println!("    N/A");
+    }
+
+    println!(
+        "  Implied Volatility: {}",
+        option
+            .implied_volatility
+            .map(|v| format!("{:.4}", v))
+            .unwrap_or_else(|| "N/A".to_string())
+    );
+    println!(
+        "  Open Interest: {}",
+        option
+            .open_interest
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "N/A".to_string())
+    );
 
-        if let Some(quote) = &option.last_quote {
-            println!(
-                "  Last Quote: Bid ${} / Ask ${}",
-                quote
-                    .bid
-                    .map(|v| format!("{:.2}", v))
-                    .unwrap_or_else(|| "N/A".to_string()),
-                quote
-                    .ask
-                    .map(|v| format!("{:.2}", v))
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-        }
-
-        if let Some(trade) = &option.last_trade {
-            println!(
-                "  Last Trade: ${} ({} contracts)",
-                trade
-                    .price
-                    .map(|v| format!("{:.2}", v))
-                    .unwrap_or_else(|| "N/A".to_string()),
-                trade
-                    .size
-                    .map(|v| v.to_string())
-                    .unwrap_or_else(|| "N/A".to_string())
-            );
-        }
-
-        println!();
+    if let Some(quote) = &option.last_quote {
+        println!(
+            "  Last Quote: Bid ${} / Ask ${}",
+            quote
+                .bid
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "N/A".to_string()),
+            quote
+                .ask
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+        println!(
+            "  Mid / Spread: {} / {}",
+            quote
+                .mid_price()
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "N/A".to_string()),
+            quote
+                .spread()
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "N/A".to_string())
+        );
     }
-}
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
-        .init();
+    if let Some(trade) = &option.last_trade {
+        println!(
+            "  Last Trade: ${} ({} contracts)",
+            trade
+                .price
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "N/A".to_string()),
+            trade
+                .size
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "N/A".to_string())
+        );
+    }
 
-    let args = Args::parse();
-    let config = Config::from_env()?;
-    let client = MassiveClient::new(&config);
+    println!();
+}
 
+async fn run_chain(client: &MassiveClient, args: ChainArgs) -> Result<()> {
     info!("Fetching options chain for {}", args.ticker);
     println!("Fetching options chain snapshot for {}...", args.ticker);
     println!("{}", "=".repeat(80));
 
-    let params = periscope::client::OptionsChainParams {
+    let params = OptionsChainParams {
         limit: Some(args.limit),
-        ..Default::default()
+        strike_price: args.strike,
+        expiration_date: args.expiration,
+        contract_type: args.contract_type,
     };
 
     let data = client.get_options_chain(&args.ticker, Some(params)).await?;
 
-    if args.json {
+    if let Some(csv_path) = &args.csv {
+        let file = File::create(csv_path)?;
+        data.to_csv(file)?;
+        println!(
+            "Wrote {} contracts to {}",
+            data.results.len(),
+            csv_path.display()
+        );
+    } else if args.json {
         println!("{}", serde_json::to_string_pretty(&data)?);
     } else {
         print_options_chain(&data);
@@ -173,3 +247,102 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+async fn run_contract(client: &MassiveClient, args: ContractArgs) -> Result<()> {
+    let symbol = OptionSymbol::parse(&args.ticker)?;
+
+    info!(
+        "Fetching contract {} (underlying {})",
+        args.ticker,
+        symbol.underlying_symbol()
+    );
+
+    let params = OptionsChainParams {
+        strike_price: Some(symbol.strike),
+        expiration_date: Some(symbol.expiration_date().format("%Y-%m-%d").to_string()),
+        contract_type: Some(
+            match symbol.contract_type {
+                ContractType::Call => "call",
+                ContractType::Put => "put",
+            }
+            .to_string(),
+        ),
+        limit: None,
+    };
+
+    let chain = client
+        .get_options_chain(symbol.underlying_symbol(), Some(params))
+        .await?;
+
+    let contract = chain
+        .results
+        .into_iter()
+        .find(|c| {
+            c.details
+                .as_ref()
+                .and_then(|d| d.ticker.as_deref())
+                .is_some_and(|ticker| ticker == args.ticker)
+        })
+        .ok_or_else(|| anyhow!("contract {} not found in chain", args.ticker))?;
+
+    print_contract(&contract);
+
+    Ok(())
+}
+
+async fn run_screen(client: &MassiveClient, args: ScreenArgs) -> Result<()> {
+    let contracts = client
+        .get_options_chain_all(&args.ticker, None, None)
+        .await?;
+
+    let matches: Vec<_> = contracts
+        .into_iter()
+        .filter(|contract| {
+            let delta = contract.greeks.as_ref().and_then(|g| g.delta);
+            let delta_ok = match delta {
+                Some(delta) => {
+                    args.min_delta.is_none_or(|min| delta >= min)
+                        && args.max_delta.is_none_or(|max| delta <= max)
+                }
+                None => args.min_delta.is_none() && args.max_delta.is_none(),
+            };
+
+            let open_interest_ok = match args.min_open_interest {
+                Some(min) => contract.open_interest.is_some_and(|oi| oi >= min),
+                None => true,
+            };
+
+            delta_ok && open_interest_ok
+        })
+        .collect();
+
+    println!(
+        "{} contract(s) matched for {}",
+        matches.len(),
+        args.ticker
+    );
+    println!("{}", "-".repeat(80));
+
+    for contract in &matches {
+        print_contract(contract);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let cli = Cli::parse();
+    let config = Config::from_env()?;
+    let client = MassiveClient::new(&config);
+
+    match cli.command {
+        Command::Chain(args) => run_chain(&client, args).await,
+        Command::Contract(args) => run_contract(&client, args).await,
+        Command::Screen(args) => run_screen(&client, args).await,
+    }
+}
@@ -1,13 +1,18 @@
 use crate::config::Config;
-use crate::error::Result;
-use crate::models::OptionsChainResponse;
-use reqwest::Client;
-use tracing::{debug, instrument};
+use crate::error::{PeriscopeError, Result};
+use crate::models::{OptionContract, OptionsChainResponse};
+use futures::stream::{self, Stream, TryStreamExt};
+use rand::Rng;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, instrument, warn};
 
 pub struct MassiveClient {
     client: Client,
     base_url: String,
     api_key: String,
+    retry_config: RetryConfig,
 }
 
 #[derive(Debug, Default)]
@@ -18,12 +23,58 @@ pub struct OptionsChainParams {
     pub limit: Option<i32>,
 }
 
+/// Controls how `MassiveClient` retries transient failures.
+///
+/// Retries use exponential backoff with full jitter: attempt `n` sleeps for
+/// `min(max_delay, base_delay * 2^n)` scaled by a random factor in `[0.5, 1.0]`,
+/// unless the response carries a `Retry-After` header, which takes precedence.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A config that performs no retries, for callers that want to handle
+    /// failures themselves.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+}
+
+const RETRYABLE_STATUSES: [StatusCode; 5] = [
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
 impl MassiveClient {
     pub fn new(config: &Config) -> Self {
+        Self::with_retry_config(config, RetryConfig::default())
+    }
+
+    pub fn with_retry_config(config: &Config, retry_config: RetryConfig) -> Self {
         Self {
             client: Client::new(),
             base_url: config.massive_base_url.clone(),
             api_key: config.massive_api_key.clone(),
+            retry_config,
         }
     }
 
@@ -33,11 +84,113 @@ impl MassiveClient {
         underlying_ticker: &str,
         params: Option<OptionsChainParams>,
     ) -> Result<OptionsChainResponse> {
-        let url = format!("{}/snapshot/options/{}", self.base_url, underlying_ticker);
-        let params = params.unwrap_or_default();
-
         debug!("Fetching options chain for {}", underlying_ticker);
 
+        let request = self.chain_request(underlying_ticker, &params.unwrap_or_default());
+
+        let response = self
+            .send_with_retry(request)
+            .await?
+            .json::<OptionsChainResponse>()
+            .await?;
+
+        debug!(
+            "Received {} contracts for {}",
+            response.results.len(),
+            underlying_ticker
+        );
+
+        Ok(response)
+    }
+
+    /// Fetches every page of an underlying's options chain, following
+    /// `next_url` until it is exhausted (or `max_pages` is reached).
+    #[instrument(skip(self, params))]
+    pub async fn get_options_chain_all(
+        &self,
+        underlying_ticker: &str,
+        params: Option<OptionsChainParams>,
+        max_pages: Option<usize>,
+    ) -> Result<Vec<OptionContract>> {
+        let mut contracts = Vec::new();
+        let mut next_request =
+            Some(self.chain_request(underlying_ticker, &params.unwrap_or_default()));
+        let mut pages = 0usize;
+
+        while let Some(request) = next_request.take() {
+            let page = self
+                .send_with_retry(request)
+                .await?
+                .json::<OptionsChainResponse>()
+                .await?;
+
+            contracts.extend(page.results);
+            pages += 1;
+
+            let reached_cap = max_pages.is_some_and(|cap| pages >= cap);
+            next_request = page
+                .next_url
+                .filter(|_| !reached_cap)
+                .map(|next_url| self.next_page_request(&next_url));
+        }
+
+        debug!(
+            "Fetched {} contracts for {} across {} page(s)",
+            contracts.len(),
+            underlying_ticker,
+            pages
+        );
+
+        Ok(contracts)
+    }
+
+    /// Like `get_options_chain_all`, but yields contracts page-by-page
+    /// instead of buffering the whole chain, so large underlyings can be
+    /// processed incrementally.
+    pub fn options_chain_stream<'a>(
+        &'a self,
+        underlying_ticker: &'a str,
+        params: Option<OptionsChainParams>,
+        max_pages: Option<usize>,
+    ) -> impl Stream<Item = Result<OptionContract>> + 'a {
+        struct State {
+            next_request: Option<RequestBuilder>,
+            pages: usize,
+        }
+
+        let state = State {
+            next_request: Some(self.chain_request(underlying_ticker, &params.unwrap_or_default())),
+            pages: 0,
+        };
+
+        stream::try_unfold(state, move |mut state| async move {
+            let Some(request) = state.next_request.take() else {
+                return Ok::<_, PeriscopeError>(None);
+            };
+
+            let page = self
+                .send_with_retry(request)
+                .await?
+                .json::<OptionsChainResponse>()
+                .await?;
+
+            state.pages += 1;
+            let reached_cap = max_pages.is_some_and(|cap| state.pages >= cap);
+            state.next_request = page
+                .next_url
+                .filter(|_| !reached_cap)
+                .map(|next_url| self.next_page_request(&next_url));
+
+            let page_stream = stream::iter(page.results.into_iter().map(Ok));
+            Ok(Some((page_stream, state)))
+        })
+        .try_flatten()
+    }
+
+    /// Builds the initial request for an underlying's options chain.
+    fn chain_request(&self, underlying_ticker: &str, params: &OptionsChainParams) -> RequestBuilder {
+        let url = format!("{}/snapshot/options/{}", self.base_url, underlying_ticker);
+
         let mut request = self
             .client
             .get(&url)
@@ -59,14 +212,230 @@ impl MassiveClient {
             request = request.query(&[("contract_type", contract_type)]);
         }
 
-        let response = request.send().await?.json::<OptionsChainResponse>().await?;
+        request
+    }
 
-        debug!(
-            "Received {} contracts for {}",
-            response.results.len(),
-            underlying_ticker
-        );
+    /// Builds a request for a `next_url` cursor, re-attaching `apiKey` since
+    /// Polygon-style cursors drop it.
+    fn next_page_request(&self, next_url: &str) -> RequestBuilder {
+        self.client.get(next_url).query(&[("apiKey", &self.api_key)])
+    }
 
-        Ok(response)
+    /// Sends `request`, retrying transient failures with exponential backoff
+    /// and mapping known HTTP statuses to `PeriscopeError` variants.
+    async fn send_with_retry(&self, request: RequestBuilder) -> Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            let attempt_request = request
+                .try_clone()
+                .expect("request body must be clonable to support retries");
+
+            let response = attempt_request.send().await?;
+            let status = response.status();
+
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            let error = match status {
+                StatusCode::UNAUTHORIZED => PeriscopeError::Unauthorized,
+                StatusCode::NOT_FOUND => PeriscopeError::NotFound(response.url().to_string()),
+                StatusCode::TOO_MANY_REQUESTS => PeriscopeError::RateLimited,
+                _ => PeriscopeError::Api(response.error_for_status_ref().unwrap_err()),
+            };
+
+            if attempt >= self.retry_config.max_retries || !RETRYABLE_STATUSES.contains(&status) {
+                return Err(error);
+            }
+
+            let delay = retry_after(&response)
+                .unwrap_or_else(|| self.backoff_delay(attempt));
+
+            warn!(
+                "Request failed with {} (attempt {}/{}), retrying in {:?}",
+                status,
+                attempt + 1,
+                self.retry_config.max_retries,
+                delay
+            );
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Scales `backoff_cap`'s pre-jitter value by a random factor in
+    /// `[0.5, 1.0]` (full jitter).
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let capped = backoff_cap(&self.retry_config, attempt);
+        let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+        capped.mul_f64(jitter)
+    }
+}
+
+/// The pre-jitter backoff cap for `attempt`: `min(max_delay, base_delay * 2^attempt)`.
+fn backoff_cap(retry_config: &RetryConfig, attempt: u32) -> Duration {
+    let exponential = retry_config
+        .base_delay
+        .saturating_mul(2u32.saturating_pow(attempt));
+    exponential.min(retry_config.max_delay)
+}
+
+/// Reads a `Retry-After` header (expressed in seconds) from a failed response.
+/// It is honored for every retryable status, not only 429.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer, retry_config: RetryConfig) -> MassiveClient {
+        let config = Config {
+            massive_api_key: "test-key".to_string(),
+            massive_base_url: server.uri(),
+        };
+        MassiveClient::with_retry_config(&config, retry_config)
+    }
+
+    fn empty_chain_response() -> serde_json::Value {
+        json!({
+            "status": "OK",
+            "request_id": "test-request",
+            "results": [],
+            "next_url": null,
+        })
+    }
+
+    #[test]
+    fn backoff_cap_is_monotonic_and_saturates_at_max_delay() {
+        let config = RetryConfig::default();
+        let mut previous = Duration::from_secs(0);
+
+        for attempt in 0..10 {
+            let cap = backoff_cap(&config, attempt);
+            assert!(cap >= previous, "cap must not shrink as attempt grows");
+            assert!(cap <= config.max_delay, "cap must never exceed max_delay");
+            previous = cap;
+        }
+
+        assert_eq!(backoff_cap(&config, 10), config.max_delay);
+    }
+
+    #[test]
+    fn backoff_delay_stays_within_jitter_bounds_of_the_cap() {
+        let config = Config {
+            massive_api_key: "test-key".to_string(),
+            massive_base_url: "http://example.invalid".to_string(),
+        };
+        let client = MassiveClient::with_retry_config(&config, RetryConfig::default());
+
+        for attempt in 0..8 {
+            let cap = backoff_cap(&client.retry_config, attempt);
+            for _ in 0..50 {
+                let delay = client.backoff_delay(attempt);
+                assert!(delay >= cap.mul_f64(0.5));
+                assert!(delay <= cap);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_401_to_unauthorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server, RetryConfig::none());
+        let err = client.get_options_chain("AAPL", None).await.unwrap_err();
+        assert!(matches!(err, PeriscopeError::Unauthorized));
+    }
+
+    #[tokio::test]
+    async fn maps_404_to_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server, RetryConfig::none());
+        let err = client.get_options_chain("AAPL", None).await.unwrap_err();
+        assert!(matches!(err, PeriscopeError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn maps_429_to_rate_limited() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server, RetryConfig::none());
+        let err = client.get_options_chain("AAPL", None).await.unwrap_err();
+        assert!(matches!(err, PeriscopeError::RateLimited));
+    }
+
+    #[tokio::test]
+    async fn retries_retryable_5xx_statuses_until_success() {
+        for status in [500, 502, 503, 504] {
+            let server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(status))
+                .up_to_n_times(2)
+                .mount(&server)
+                .await;
+
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(empty_chain_response()))
+                .mount(&server)
+                .await;
+
+            let retry_config = RetryConfig {
+                max_retries: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+            };
+            let client = client_for(&server, retry_config);
+
+            let response = client
+                .get_options_chain("AAPL", None)
+                .await
+                .unwrap_or_else(|e| panic!("status {status} should have been retried: {e}"));
+            assert!(response.results.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_retryable_4xx_statuses() {
+        for status in [400, 403] {
+            let server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(status))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let client = client_for(&server, RetryConfig::default());
+            let result = client.get_options_chain("AAPL", None).await;
+            assert!(result.is_err(), "status {status} should not be retried");
+        }
     }
 }
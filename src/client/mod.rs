@@ -0,0 +1,3 @@
+mod massive;
+
+pub use massive::{MassiveClient, OptionsChainParams, RetryConfig};
@@ -25,6 +25,9 @@ pub enum PeriscopeError {
 
     #[error("Resource not found: {0}")]
     NotFound(String),
+
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
 }
 
 pub type Result<T> = std::result::Result<T, PeriscopeError>;
@@ -0,0 +1,221 @@
+use super::{ContractDetails, DayBar, Greeks, LastQuote, LastTrade, OptionContract, OptionsChainResponse};
+use crate::error::{PeriscopeError, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// Flat, spreadsheet-friendly view of a single `OptionContract`, one row per
+/// contract. Missing values round-trip as the literal string `N/A`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OptionRow {
+    #[serde(rename = "Symbol")]
+    symbol: String,
+    #[serde(rename = "Type")]
+    contract_type: String,
+    #[serde(rename = "Strike")]
+    strike_price: String,
+    #[serde(rename = "Expiration")]
+    expiration_date: String,
+    #[serde(rename = "Delta")]
+    delta: String,
+    #[serde(rename = "Gamma")]
+    gamma: String,
+    #[serde(rename = "Theta")]
+    theta: String,
+    #[serde(rename = "Vega")]
+    vega: String,
+    #[serde(rename = "Implied Volatility")]
+    implied_volatility: String,
+    #[serde(rename = "Open Interest")]
+    open_interest: String,
+    #[serde(rename = "Bid")]
+    bid: String,
+    #[serde(rename = "Ask")]
+    ask: String,
+    #[serde(rename = "Mid")]
+    mid_price: String,
+    #[serde(rename = "Spread")]
+    spread: String,
+    #[serde(rename = "Last Price")]
+    last_price: String,
+    #[serde(rename = "Last Size")]
+    last_size: String,
+    #[serde(rename = "Day Open")]
+    day_open: String,
+    #[serde(rename = "Day High")]
+    day_high: String,
+    #[serde(rename = "Day Low")]
+    day_low: String,
+    #[serde(rename = "Day Close")]
+    day_close: String,
+    #[serde(rename = "Day Volume")]
+    day_volume: String,
+}
+
+fn fmt_opt<T: ToString>(value: Option<T>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "N/A".to_string())
+}
+
+fn parse_opt<T: std::str::FromStr>(field: &str) -> Result<Option<T>> {
+    if field.is_empty() || field == "N/A" {
+        Ok(None)
+    } else {
+        field
+            .parse()
+            .map(Some)
+            .map_err(|_| PeriscopeError::InvalidInput(format!("could not parse CSV field {field:?}")))
+    }
+}
+
+impl From<&OptionContract> for OptionRow {
+    fn from(contract: &OptionContract) -> Self {
+        let details = contract.details.as_ref();
+        let greeks = contract.greeks.as_ref();
+        let quote = contract.last_quote.as_ref();
+        let trade = contract.last_trade.as_ref();
+        let day = contract.day.as_ref();
+
+        Self {
+            symbol: details
+                .and_then(|d| d.ticker.clone())
+                .unwrap_or_else(|| "N/A".to_string()),
+            contract_type: details
+                .and_then(|d| d.contract_type.clone())
+                .unwrap_or_else(|| "N/A".to_string()),
+            strike_price: fmt_opt(details.and_then(|d| d.strike_price)),
+            expiration_date: details
+                .and_then(|d| d.expiration_date.clone())
+                .unwrap_or_else(|| "N/A".to_string()),
+            delta: fmt_opt(greeks.and_then(|g| g.delta)),
+            gamma: fmt_opt(greeks.and_then(|g| g.gamma)),
+            theta: fmt_opt(greeks.and_then(|g| g.theta)),
+            vega: fmt_opt(greeks.and_then(|g| g.vega)),
+            implied_volatility: fmt_opt(contract.implied_volatility),
+            open_interest: fmt_opt(contract.open_interest),
+            bid: fmt_opt(quote.and_then(|q| q.bid)),
+            ask: fmt_opt(quote.and_then(|q| q.ask)),
+            mid_price: fmt_opt(quote.and_then(|q| q.mid_price())),
+            spread: fmt_opt(quote.and_then(|q| q.spread())),
+            last_price: fmt_opt(trade.and_then(|t| t.price)),
+            last_size: fmt_opt(trade.and_then(|t| t.size)),
+            day_open: fmt_opt(day.and_then(|d| d.open)),
+            day_high: fmt_opt(day.and_then(|d| d.high)),
+            day_low: fmt_opt(day.and_then(|d| d.low)),
+            day_close: fmt_opt(day.and_then(|d| d.close)),
+            day_volume: fmt_opt(day.and_then(|d| d.volume)),
+        }
+    }
+}
+
+impl TryFrom<OptionRow> for OptionContract {
+    type Error = PeriscopeError;
+
+    fn try_from(row: OptionRow) -> Result<Self> {
+        let non_na = |s: String| if s.is_empty() || s == "N/A" { None } else { Some(s) };
+
+        let details = ContractDetails {
+            ticker: non_na(row.symbol),
+            contract_type: non_na(row.contract_type),
+            strike_price: parse_opt(&row.strike_price)?,
+            expiration_date: non_na(row.expiration_date),
+            exercise_style: None,
+            shares_per_contract: None,
+        };
+
+        let greeks = Greeks {
+            delta: parse_opt(&row.delta)?,
+            gamma: parse_opt(&row.gamma)?,
+            theta: parse_opt(&row.theta)?,
+            vega: parse_opt(&row.vega)?,
+        };
+
+        let last_quote = LastQuote {
+            bid: parse_opt(&row.bid)?,
+            ask: parse_opt(&row.ask)?,
+            bid_size: None,
+            ask_size: None,
+        };
+
+        let last_trade = LastTrade {
+            price: parse_opt(&row.last_price)?,
+            size: parse_opt(&row.last_size)?,
+        };
+
+        let day = DayBar {
+            open: parse_opt(&row.day_open)?,
+            high: parse_opt(&row.day_high)?,
+            low: parse_opt(&row.day_low)?,
+            close: parse_opt(&row.day_close)?,
+            volume: parse_opt(&row.day_volume)?,
+        };
+
+        Ok(OptionContract {
+            details: Some(details),
+            greeks: Some(greeks),
+            implied_volatility: parse_opt(&row.implied_volatility)?,
+            open_interest: parse_opt(&row.open_interest)?,
+            last_quote: Some(last_quote),
+            last_trade: Some(last_trade),
+            day: Some(day),
+            underlying_asset: None,
+        })
+    }
+}
+
+/// Header row written by `to_csv`, in column order. Kept in lockstep with
+/// `OptionRow`'s `#[serde(rename)]` attributes.
+const HEADERS: &[&str] = &[
+    "Symbol",
+    "Type",
+    "Strike",
+    "Expiration",
+    "Delta",
+    "Gamma",
+    "Theta",
+    "Vega",
+    "Implied Volatility",
+    "Open Interest",
+    "Bid",
+    "Ask",
+    "Mid",
+    "Spread",
+    "Last Price",
+    "Last Size",
+    "Day Open",
+    "Day High",
+    "Day Low",
+    "Day Close",
+    "Day Volume",
+];
+
+impl OptionsChainResponse {
+    /// Flattens each contract into a CSV row (see `OptionRow`), writing
+    /// `N/A` for missing fields. The header row is always written, even for
+    /// an empty chain, so a zero-row export is still recognizable as a CSV.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<()> {
+        let mut csv_writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(writer);
+
+        csv_writer.write_record(HEADERS)?;
+
+        for contract in &self.results {
+            csv_writer.serialize(OptionRow::from(contract))?;
+        }
+
+        csv_writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+}
+
+/// Reconstructs the contracts written by `OptionsChainResponse::to_csv`, for
+/// offline analysis of a previously exported chain.
+pub fn from_csv<R: Read>(reader: R) -> Result<Vec<OptionContract>> {
+    let mut csv_reader = csv::Reader::from_reader(reader);
+
+    csv_reader
+        .deserialize::<OptionRow>()
+        .map(|row| row.map_err(PeriscopeError::from).and_then(OptionContract::try_from))
+        .collect()
+}
@@ -1,7 +1,11 @@
+mod csv_export;
 mod greeks;
+mod option_symbol;
 mod options;
 
+pub use csv_export::from_csv;
 pub use greeks::Greeks;
+pub use option_symbol::OptionSymbol;
 pub use options::{
     ContractDetails, ContractType, DayBar, ExerciseStyle, LastQuote, LastTrade, OptionContract,
     OptionsChainResponse, UnderlyingAsset,
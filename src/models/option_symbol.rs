@@ -0,0 +1,123 @@
+use super::ContractType;
+use crate::error::{PeriscopeError, Result};
+use chrono::NaiveDate;
+use std::str::FromStr;
+
+/// A decoded Polygon/OCC option ticker, e.g. `O:AAPL251219C00150000`.
+///
+/// Grammar: an optional `O:` prefix, the underlying root (letters up to the
+/// first digit), a 6-digit expiration (`YYMMDD`), a single `C`/`P` side, and
+/// an 8-digit strike expressed in thousandths of a dollar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    pub underlying: String,
+    pub expiration: NaiveDate,
+    pub contract_type: ContractType,
+    pub strike: f64,
+}
+
+impl OptionSymbol {
+    pub fn parse(ticker: &str) -> Result<Self> {
+        let body = ticker.strip_prefix("O:").unwrap_or(ticker);
+
+        let digits_at = body
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(|| invalid(ticker))?;
+
+        if digits_at == 0 || !body[..digits_at].chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid(ticker));
+        }
+
+        let underlying = body[..digits_at].to_string();
+        let rest = &body[digits_at..];
+
+        if rest.len() != 15 || !rest.is_char_boundary(6) || !rest.is_char_boundary(7) {
+            return Err(invalid(ticker));
+        }
+
+        let expiration = NaiveDate::parse_from_str(&rest[0..6], "%y%m%d")
+            .map_err(|_| invalid(ticker))?;
+
+        let contract_type = match &rest[6..7] {
+            "C" => ContractType::Call,
+            "P" => ContractType::Put,
+            _ => return Err(invalid(ticker)),
+        };
+
+        let strike_thousandths: u64 = rest[7..15].parse().map_err(|_| invalid(ticker))?;
+
+        Ok(Self {
+            underlying,
+            expiration,
+            contract_type,
+            strike: strike_thousandths as f64 / 1000.0,
+        })
+    }
+
+    pub fn underlying_symbol(&self) -> &str {
+        &self.underlying
+    }
+
+    pub fn expiration_date(&self) -> NaiveDate {
+        self.expiration
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = PeriscopeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        OptionSymbol::parse(s)
+    }
+}
+
+fn invalid(ticker: &str) -> PeriscopeError {
+    PeriscopeError::InvalidInput(format!("not a valid OCC option ticker: {ticker}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prefixed_ticker() {
+        let symbol = OptionSymbol::parse("O:AAPL251219C00150000").unwrap();
+        assert_eq!(symbol.underlying, "AAPL");
+        assert_eq!(symbol.expiration, NaiveDate::from_ymd_opt(2025, 12, 19).unwrap());
+        assert_eq!(symbol.contract_type, ContractType::Call);
+        assert_eq!(symbol.strike, 150.0);
+    }
+
+    #[test]
+    fn parses_bare_ticker_without_prefix() {
+        let symbol = OptionSymbol::parse("AAPL251219P00150000").unwrap();
+        assert_eq!(symbol.underlying, "AAPL");
+        assert_eq!(symbol.contract_type, ContractType::Put);
+        assert_eq!(symbol.strike, 150.0);
+    }
+
+    #[test]
+    fn rejects_wrong_length_suffix() {
+        // Expiration digit dropped, so the suffix is 14 bytes instead of 15.
+        assert!(OptionSymbol::parse("O:AAPL25121C00150000").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_side_character() {
+        assert!(OptionSymbol::parse("O:AAPL251219X00150000").is_err());
+    }
+
+    #[test]
+    fn rejects_non_digit_strike() {
+        assert!(OptionSymbol::parse("O:AAPL251219C0015000A").is_err());
+    }
+
+    #[test]
+    fn rejects_multi_byte_ticker_without_panicking() {
+        // A 2-byte UTF-8 character ('é') is placed so it straddles byte
+        // offset 6 of the 15-byte suffix, which is exactly where `parse`
+        // checks `is_char_boundary` before slicing out the side character.
+        let ticker = "O:AAPL5AAAA\u{e9}BBBBBBBB";
+        assert!(OptionSymbol::parse(ticker).is_err());
+    }
+}
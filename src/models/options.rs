@@ -1,4 +1,6 @@
-use super::Greeks;
+use super::{Greeks, OptionSymbol};
+use crate::error::{PeriscopeError, Result};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -50,6 +52,51 @@ impl ContractDetails {
                 _ => None,
             })
     }
+
+    /// Parses `ticker` into an `OptionSymbol`, cross-validating the decoded
+    /// fields against `strike_price`, `expiration_date`, and `contract_type`
+    /// when those are present. Returns `Ok(None)` if there is no ticker to
+    /// parse, and `Err(PeriscopeError::InvalidInput)` if parsing fails or the
+    /// decoded fields disagree with the explicit ones.
+    pub fn parse_symbol(&self) -> Result<Option<OptionSymbol>> {
+        let Some(ticker) = &self.ticker else {
+            return Ok(None);
+        };
+
+        let symbol = OptionSymbol::parse(ticker)?;
+
+        if let Some(strike_price) = self.strike_price {
+            if (symbol.strike - strike_price).abs() > 0.0005 {
+                return Err(PeriscopeError::InvalidInput(format!(
+                    "ticker {ticker} encodes strike {} but strike_price is {strike_price}",
+                    symbol.strike
+                )));
+            }
+        }
+
+        if let Some(expiration_date) = &self.expiration_date {
+            let expected = NaiveDate::parse_from_str(expiration_date, "%Y-%m-%d").map_err(|_| {
+                PeriscopeError::InvalidInput(format!("invalid expiration_date {expiration_date}"))
+            })?;
+            if symbol.expiration != expected {
+                return Err(PeriscopeError::InvalidInput(format!(
+                    "ticker {ticker} encodes expiration {} but expiration_date is {expiration_date}",
+                    symbol.expiration
+                )));
+            }
+        }
+
+        if let Some(contract_type) = self.contract_type_enum() {
+            if symbol.contract_type != contract_type {
+                return Err(PeriscopeError::InvalidInput(format!(
+                    "ticker {ticker} encodes a {:?} but contract_type is {:?}",
+                    symbol.contract_type, contract_type
+                )));
+            }
+        }
+
+        Ok(Some(symbol))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]